@@ -0,0 +1,90 @@
+//! Shared target-triple mapping used by both the loader scavenger and the
+//! pinned SDK downloader.
+
+/// The SDK's per-architecture folder name, as used inside the
+/// `Microsoft.Web.WebView2` NuGet package layout.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SdkArch {
+    X86,
+    X64,
+    Arm64,
+}
+
+impl SdkArch {
+    /// Maps `CARGO_CFG_TARGET_ARCH` to the SDK folder that ships the
+    /// matching loader.
+    pub fn from_cargo_arch(arch: &str) -> Option<Self> {
+        match arch {
+            "x86" => Some(Self::X86),
+            "x86_64" => Some(Self::X64),
+            "aarch64" => Some(Self::Arm64),
+            _ => None,
+        }
+    }
+
+    pub fn sdk_folder(self) -> &'static str {
+        match self {
+            Self::X86 => "x86",
+            Self::X64 => "x64",
+            Self::Arm64 => "arm64",
+        }
+    }
+}
+
+/// Which Windows toolchain we're linking for. The GNU target triples (MinGW)
+/// can't consume the MSVC import library directly, so the loader's lib/DLL
+/// pairing differs from MSVC.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TargetEnv {
+    Msvc,
+    Gnu,
+}
+
+impl TargetEnv {
+    pub fn from_cargo_env(env: &str) -> Self {
+        if env == "gnu" {
+            Self::Gnu
+        } else {
+            // Treat anything else (msvc, or unset in edge cases) as MSVC,
+            // which is the common case and matches prior behavior.
+            Self::Msvc
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sdk_arch_maps_known_triples() {
+        assert_eq!(SdkArch::from_cargo_arch("x86"), Some(SdkArch::X86));
+        assert_eq!(SdkArch::from_cargo_arch("x86_64"), Some(SdkArch::X64));
+        assert_eq!(SdkArch::from_cargo_arch("aarch64"), Some(SdkArch::Arm64));
+    }
+
+    #[test]
+    fn sdk_arch_rejects_unknown_triples() {
+        assert_eq!(SdkArch::from_cargo_arch("riscv64"), None);
+        assert_eq!(SdkArch::from_cargo_arch(""), None);
+    }
+
+    #[test]
+    fn sdk_folder_matches_nuget_layout() {
+        assert_eq!(SdkArch::X86.sdk_folder(), "x86");
+        assert_eq!(SdkArch::X64.sdk_folder(), "x64");
+        assert_eq!(SdkArch::Arm64.sdk_folder(), "arm64");
+    }
+
+    #[test]
+    fn target_env_recognizes_gnu() {
+        assert_eq!(TargetEnv::from_cargo_env("gnu"), TargetEnv::Gnu);
+    }
+
+    #[test]
+    fn target_env_defaults_to_msvc() {
+        assert_eq!(TargetEnv::from_cargo_env("msvc"), TargetEnv::Msvc);
+        assert_eq!(TargetEnv::from_cargo_env(""), TargetEnv::Msvc);
+        assert_eq!(TargetEnv::from_cargo_env("newlib"), TargetEnv::Msvc);
+    }
+}
@@ -0,0 +1,177 @@
+//! Shared `copy_if_changed` helper used by every sidecar-copying step in this
+//! build script (the loader DLL, extra sidecar DLLs, the Evergreen
+//! bootstrapper).
+//!
+//! Compared to a bare `std::fs::copy`, this:
+//! - skips the copy when the destination already has identical content, so
+//!   incremental builds don't rewrite unchanged DLLs on every run;
+//! - never leaves a partially-written file at `to` on the normal path, by
+//!   copying into a temp file in the destination directory first and
+//!   renaming it into place;
+//! - falls back to a plain, non-atomic copy straight to `to` if that rename
+//!   fails with a cross-device error (`EXDEV`).
+
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::Path;
+
+/// Files at or below this size are compared byte-for-byte; larger files are
+/// compared by size plus their first and last block, which is enough to
+/// catch the common case (a rebuilt DLL) without reading the whole thing.
+const FULL_COMPARE_THRESHOLD: u64 = 64 * 1024;
+const BLOCK_SIZE: usize = 4096;
+
+/// Copies `from` to `to`, skipping the write if `to` already has the same
+/// content. Returns `Ok(true)` if a copy was performed, `Ok(false)` if the
+/// destination was already up to date.
+pub fn copy_if_changed(from: &Path, to: &Path) -> io::Result<bool> {
+    if let Some(parent) = to.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    if to.exists() && contents_match(from, to)? {
+        return Ok(false);
+    }
+
+    let tmp_path = temp_path_for(to);
+    fs::copy(from, &tmp_path)?;
+
+    match fs::rename(&tmp_path, to) {
+        Ok(()) => Ok(true),
+        Err(e) if is_cross_device(&e) => {
+            // The rename of the temp file onto `to` reported a cross-device
+            // error. Fall back to a plain copy, then clean up the temp file
+            // we created above.
+            let result = fs::copy(from, to).map(|_| true);
+            let _ = fs::remove_file(&tmp_path);
+            result
+        }
+        Err(e) => {
+            let _ = fs::remove_file(&tmp_path);
+            Err(e)
+        }
+    }
+}
+
+fn temp_path_for(to: &Path) -> std::path::PathBuf {
+    let file_name = to.file_name().and_then(|n| n.to_str()).unwrap_or("copy_if_changed");
+    to.with_file_name(format!(".{file_name}.{}.tmp", std::process::id()))
+}
+
+fn is_cross_device(e: &io::Error) -> bool {
+    // EXDEV on Unix, ERROR_NOT_SAME_DEVICE on Windows.
+    matches!(e.raw_os_error(), Some(18) | Some(17))
+}
+
+fn contents_match(from: &Path, to: &Path) -> io::Result<bool> {
+    let from_len = fs::metadata(from)?.len();
+    let to_len = fs::metadata(to)?.len();
+    if from_len != to_len {
+        return Ok(false);
+    }
+
+    if from_len <= FULL_COMPARE_THRESHOLD {
+        return Ok(fs::read(from)? == fs::read(to)?);
+    }
+
+    let mut from_file = File::open(from)?;
+    let mut to_file = File::open(to)?;
+
+    let mut from_head = [0u8; BLOCK_SIZE];
+    let mut to_head = [0u8; BLOCK_SIZE];
+    from_file.read_exact(&mut from_head)?;
+    to_file.read_exact(&mut to_head)?;
+    if from_head != to_head {
+        return Ok(false);
+    }
+
+    Ok(read_tail(&mut from_file, from_len)? == read_tail(&mut to_file, to_len)?)
+}
+
+fn read_tail(file: &mut File, len: u64) -> io::Result<[u8; BLOCK_SIZE]> {
+    use std::io::{Seek, SeekFrom};
+    let mut tail = [0u8; BLOCK_SIZE];
+    let offset = len.saturating_sub(BLOCK_SIZE as u64);
+    file.seek(SeekFrom::Start(offset))?;
+    file.read_exact(&mut tail)?;
+    Ok(tail)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Each test gets its own subdirectory of `std::env::temp_dir()`, named
+    // after the process id and the test, so parallel test runs don't collide.
+    fn scratch_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("copy_if_changed-test-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn copies_into_a_directory_that_does_not_exist_yet() {
+        let dir = scratch_dir("new-parent");
+        let from = dir.join("src.bin");
+        fs::write(&from, b"hello").unwrap();
+        let to = dir.join("nested").join("dest.bin");
+
+        assert!(copy_if_changed(&from, &to).unwrap());
+        assert_eq!(fs::read(&to).unwrap(), b"hello");
+    }
+
+    #[test]
+    fn skips_the_copy_when_destination_already_matches() {
+        let dir = scratch_dir("skip-unchanged");
+        let from = dir.join("src.bin");
+        let to = dir.join("dest.bin");
+        fs::write(&from, b"same content").unwrap();
+
+        assert!(copy_if_changed(&from, &to).unwrap(), "first copy should happen");
+        assert!(!copy_if_changed(&from, &to).unwrap(), "second copy should be skipped");
+    }
+
+    #[test]
+    fn recopies_when_destination_content_differs() {
+        let dir = scratch_dir("recopy-changed");
+        let from = dir.join("src.bin");
+        let to = dir.join("dest.bin");
+        fs::write(&from, b"version one").unwrap();
+        assert!(copy_if_changed(&from, &to).unwrap());
+
+        fs::write(&from, b"version two, a different length").unwrap();
+        assert!(copy_if_changed(&from, &to).unwrap());
+        assert_eq!(fs::read(&to).unwrap(), b"version two, a different length");
+    }
+
+    #[test]
+    fn compares_large_files_by_head_and_tail_block() {
+        let dir = scratch_dir("large-files");
+        let from = dir.join("src.bin");
+        let to = dir.join("dest.bin");
+
+        // Bigger than FULL_COMPARE_THRESHOLD so this exercises the
+        // head/tail-block comparison path instead of the full-file one.
+        let mut content = vec![0xABu8; FULL_COMPARE_THRESHOLD as usize + BLOCK_SIZE];
+        fs::write(&from, &content).unwrap();
+        assert!(copy_if_changed(&from, &to).unwrap());
+        assert!(!copy_if_changed(&from, &to).unwrap(), "identical large files should be skipped");
+
+        // A change in the tail block must be caught.
+        *content.last_mut().unwrap() = 0xFF;
+        fs::write(&from, &content).unwrap();
+        assert!(copy_if_changed(&from, &to).unwrap(), "a change in the tail block must be caught");
+    }
+
+    #[test]
+    fn is_cross_device_recognizes_exdev_and_windows_equivalent() {
+        let exdev = io::Error::from_raw_os_error(18);
+        let windows_equivalent = io::Error::from_raw_os_error(17);
+        let unrelated = io::Error::from_raw_os_error(2); // ENOENT
+
+        assert!(is_cross_device(&exdev));
+        assert!(is_cross_device(&windows_equivalent));
+        assert!(!is_cross_device(&unrelated));
+    }
+}
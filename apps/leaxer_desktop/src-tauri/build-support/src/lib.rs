@@ -0,0 +1,14 @@
+//! Build-script logic that needs to actually run under `cargo test`.
+//!
+//! `build.rs` (and anything it pulls in via `#[path]`) is never a `cargo
+//! test` target, so a `#[cfg(test)]` module living there silently never
+//! executes no matter how it's gated. The pieces here have no inherent need
+//! to run from inside a build script — they're pure mappings from env/path
+//! inputs to outputs — so they live in this ordinary lib crate instead, and
+//! `build.rs` consumes it as a build-dependency.
+
+mod arch;
+mod fs_util;
+
+pub use arch::{SdkArch, TargetEnv};
+pub use fs_util::copy_if_changed;
@@ -1,35 +1,36 @@
+// Unconditional (not `#[cfg(windows)]`): this keeps the module declared on
+// every host so `cargo clippy --all-targets` still checks it off Windows.
+// None of its items are ever referenced outside the `#[cfg(windows)]` blocks
+// in `main()` below, so both `dead_code` (the items themselves) and
+// `unused_imports` (the `pub use` re-exports in `build_support/mod.rs`) need
+// allowing on non-Windows hosts.
+#[cfg_attr(not(windows), allow(dead_code, unused_imports))]
+#[path = "build_support/mod.rs"]
+mod build_support;
+
 fn main() {
     tauri_build::build();
 
-    // Copy WebView2Loader.dll to the output directory
-    // The webview2-com-sys crate builds it but doesn't copy it to the final location
+    // Copy WebView2Loader.dll (and any other sidecar DLLs/files configured
+    // below) to the output directory. The webview2-com-sys crate builds the
+    // loader but doesn't copy it to the final location.
     #[cfg(windows)]
     {
-        use std::env;
-        use std::path::Path;
-
-        let out_dir = env::var("OUT_DIR").unwrap();
-        let profile = env::var("PROFILE").unwrap();
-
-        // Find the WebView2Loader.dll in the build output
-        let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
-        let target_dir = Path::new(&manifest_dir).join("target").join(&profile);
+        let sdk_dir = build_support::setup_webview2().expect("failed to set up WebView2Loader");
 
-        // Source: built by webview2-com-sys
-        let src_dll = Path::new(&out_dir)
-            .ancestors()
-            .find(|p| p.file_name().map(|n| n.to_str().unwrap_or("").starts_with("webview2-com-sys")).unwrap_or(false))
-            .map(|p| p.join("out").join("x64").join("WebView2Loader.dll"));
+        let extra_dlls = build_support::WindowsAttributes::new()
+            .bootstrapper_from_env("LEAXER_WEBVIEW2_BOOTSTRAPPER_SRC");
+        build_support::copy_sidecar_dlls(&extra_dlls, sdk_dir.as_deref())
+            .expect("failed to copy sidecar DLLs");
+    }
 
-        if let Some(src) = src_dll {
-            if src.exists() {
-                let dest = target_dir.join("WebView2Loader.dll");
-                if let Err(e) = std::fs::copy(&src, &dest) {
-                    println!("cargo:warning=Failed to copy WebView2Loader.dll: {}", e);
-                } else {
-                    println!("cargo:warning=Copied WebView2Loader.dll to {:?}", dest);
-                }
-            }
-        }
+    // Generate the runtime-missing check the app `include!`s so it can warn
+    // users instead of crashing when the Evergreen WebView2 runtime isn't
+    // installed.
+    #[cfg(windows)]
+    {
+        let out_dir = std::path::PathBuf::from(std::env::var("OUT_DIR").unwrap());
+        build_support::generate_runtime_check(&out_dir)
+            .expect("failed to generate webview2_runtime_check");
     }
 }
@@ -0,0 +1,105 @@
+//! Generates a small `webview2_runtime_check` module that the app
+//! `include!`s so it can detect a missing Evergreen WebView2 runtime and show
+//! a friendly dialog instead of crashing deep inside webview creation.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Registry GUID Microsoft uses for the per-machine Evergreen WebView2
+/// Runtime client entry under `...\EdgeUpdate\Clients\{GUID}`.
+const EDGE_WEBVIEW2_CLIENT_GUID: &str = "{F3017226-FE2A-4295-8BDF-00C3A9A7E4C5}";
+
+const GENERATED_FILE_NAME: &str = "webview2_runtime_check.rs";
+
+/// Writes `OUT_DIR/webview2_runtime_check.rs`. The app pulls it in with:
+///
+/// ```ignore
+/// include!(concat!(env!("OUT_DIR"), "/webview2_runtime_check.rs"));
+/// ```
+///
+/// Copying the standalone Evergreen bootstrapper next to the binary is a
+/// separate, opt-in concern handled by
+/// [`WindowsAttributes::bootstrapper_from_env`](super::sidecar::WindowsAttributes::bootstrapper_from_env)
+/// so installers ship it through the same mechanism as any other sidecar
+/// file instead of a one-off copy step here.
+pub fn generate_runtime_check(out_dir: &Path) -> io::Result<()> {
+    let dest = out_dir.join(GENERATED_FILE_NAME);
+    fs::write(&dest, RUNTIME_CHECK_SOURCE.replace("__CLIENT_GUID__", EDGE_WEBVIEW2_CLIENT_GUID))?;
+    Ok(())
+}
+
+const RUNTIME_CHECK_SOURCE: &str = r#"
+// Generated by build_support::codegen — do not edit by hand.
+
+/// Returns the installed Evergreen WebView2 Runtime version, or `None` if no
+/// usable runtime is present. Apps should check this before creating a
+/// webview and show an install prompt instead of letting webview creation
+/// fail with an opaque error.
+pub fn webview2_runtime_version() -> Option<String> {
+    if let Some(version) = webview2_runtime_version_from_api() {
+        return Some(version);
+    }
+    webview2_runtime_version_from_registry()
+}
+
+fn webview2_runtime_version_from_api() -> Option<String> {
+    use windows::core::PWSTR;
+
+    unsafe {
+        let mut raw_version = PWSTR::null();
+        let hr = webview2_com::Microsoft::Web::WebView2::Win32::GetAvailableCoreWebView2BrowserVersionString(
+            windows::core::PCWSTR::null(),
+            &mut raw_version,
+        );
+        if hr.is_err() || raw_version.is_null() {
+            return None;
+        }
+
+        let version = raw_version.to_string().ok();
+        windows::Win32::System::Com::CoTaskMemFree(Some(raw_version.0 as *const _));
+        version.filter(|v| !v.is_empty())
+    }
+}
+
+fn webview2_runtime_version_from_registry() -> Option<String> {
+    use windows::core::w;
+    use windows::Win32::System::Registry::{
+        RegGetValueW, HKEY_LOCAL_MACHINE, RRF_RT_REG_SZ,
+    };
+
+    // 32-bit installs register under the WOW6432Node view on 64-bit
+    // Windows; 64-bit installs register under the native view. Probe both
+    // so a native-view-only install isn't misreported as missing. Only
+    // HKEY_LOCAL_MACHINE is read; per-user installs are not looked up here.
+    const SUBKEYS: [windows::core::PCWSTR; 2] = [
+        w!("SOFTWARE\\WOW6432Node\\Microsoft\\EdgeUpdate\\Clients\\__CLIENT_GUID__"),
+        w!("SOFTWARE\\Microsoft\\EdgeUpdate\\Clients\\__CLIENT_GUID__"),
+    ];
+
+    for subkey in SUBKEYS {
+        unsafe {
+            let mut buf = [0u16; 64];
+            let mut len = (buf.len() * 2) as u32;
+            let status = RegGetValueW(
+                HKEY_LOCAL_MACHINE,
+                subkey,
+                w!("pv"),
+                RRF_RT_REG_SZ,
+                None,
+                Some(buf.as_mut_ptr() as *mut _),
+                Some(&mut len),
+            );
+            if status.is_err() {
+                continue;
+            }
+            let chars = (len as usize / 2).saturating_sub(1);
+            let version = String::from_utf16_lossy(&buf[..chars]);
+            if !version.is_empty() {
+                return Some(version);
+            }
+        }
+    }
+    None
+}
+"#;
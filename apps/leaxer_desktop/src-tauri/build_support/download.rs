@@ -0,0 +1,133 @@
+//! Pins the WebView2 SDK to an exact version and downloads it on demand,
+//! instead of relying on whatever version `webview2-com-sys` happened to
+//! vendor.
+//!
+//! The version comes from [`LEAXER_WEBVIEW2_VERSION`] when it is set and from
+//! the built-in default otherwise, so a version is always resolved. Any failure
+//! (no network, package layout mismatch, ...) is logged as a `cargo:warning`
+//! so the caller can fall back to scavenging `webview2-com-sys`'s own build
+//! output instead of hard-failing the build.
+//!
+//! [`LEAXER_WEBVIEW2_VERSION`]: ENV_VERSION
+
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use build_support::SdkArch;
+
+/// Version used when [`ENV_VERSION`] isn't set. Bump this alongside testing
+/// against a newer WebView2 SDK.
+const WEBVIEW2_VERSION: &str = "1.0.2739.15";
+
+/// Overrides [`WEBVIEW2_VERSION`] for local testing or pinning to a specific
+/// release without editing this file.
+const ENV_VERSION: &str = "LEAXER_WEBVIEW2_VERSION";
+
+/// Files pulled out of the NuGet package for a resolved architecture.
+pub struct PinnedLoader {
+    /// Directory (under `OUT_DIR`) containing `WebView2Loader.dll` for the
+    /// requested arch, plus `WebView2LoaderStatic.lib` when the package had it.
+    pub dir: PathBuf,
+}
+
+/// Downloads (or reuses a cached copy of) the pinned WebView2 SDK and
+/// returns the directory holding the loader DLL/static lib for `arch`.
+///
+/// Returns `None` on any failure; callers should fall back to scavenging
+/// `webview2-com-sys`'s build output.
+pub fn ensure_pinned_loader(out_dir: &Path, arch: SdkArch) -> Option<PinnedLoader> {
+    println!("cargo:rerun-if-env-changed={ENV_VERSION}");
+
+    let version = env::var(ENV_VERSION).unwrap_or_else(|_| WEBVIEW2_VERSION.to_string());
+    let cache_dir = out_dir
+        .join("webview2-sdk-cache")
+        .join(&version)
+        .join(arch.sdk_folder());
+
+    if cache_dir.join("WebView2Loader.dll").exists() {
+        return Some(PinnedLoader { dir: cache_dir });
+    }
+
+    match fetch_and_extract(&version, arch, &cache_dir) {
+        Ok(()) => Some(PinnedLoader { dir: cache_dir }),
+        Err(e) => {
+            println!("cargo:warning=Failed to fetch pinned WebView2 SDK {version}: {e}");
+            None
+        }
+    }
+}
+
+fn fetch_and_extract(version: &str, arch: SdkArch, cache_dir: &Path) -> io::Result<()> {
+    let url = format!("https://www.nuget.org/api/v2/package/Microsoft.Web.WebView2/{version}");
+
+    let response = ureq::get(&url)
+        .call()
+        .map_err(|e| io::Error::other(format!("GET {url} failed: {e}")))?;
+
+    let mut nupkg_bytes = Vec::new();
+    response
+        .into_reader()
+        .read_to_end(&mut nupkg_bytes)
+        .map_err(|e| io::Error::other(format!("reading response body: {e}")))?;
+
+    let mut archive = zip::ZipArchive::new(io::Cursor::new(nupkg_bytes))
+        .map_err(|e| io::Error::other(format!("not a valid nupkg: {e}")))?;
+
+    fs::create_dir_all(cache_dir)?;
+
+    // The package lays native binaries out as
+    // `runtimes/win-<arch>/native/...` for newer SDKs; fall back to the
+    // older flat `build/native/<arch>/...` layout for older versions.
+    let arch_segment = match arch {
+        SdkArch::X86 => "win-x86",
+        SdkArch::X64 => "win-x64",
+        SdkArch::Arm64 => "win-arm64",
+    };
+    let candidates = [
+        format!("runtimes/{arch_segment}/native/WebView2Loader.dll"),
+        format!("build/native/{}/WebView2Loader.dll", arch.sdk_folder()),
+    ];
+
+    let mut found_dll = false;
+    for name in ["WebView2Loader.dll", "WebView2LoaderStatic.lib"] {
+        for candidate in &candidates {
+            let candidate = candidate.replace("WebView2Loader.dll", name);
+            if let Ok(mut entry) = archive.by_name(&candidate) {
+                let mut bytes = Vec::with_capacity(entry.size() as usize);
+                entry.read_to_end(&mut bytes)?;
+                write_atomic(&cache_dir.join(name), &bytes)?;
+                if name == "WebView2Loader.dll" {
+                    found_dll = true;
+                }
+                break;
+            }
+        }
+    }
+
+    if found_dll {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("WebView2Loader.dll not found in package for {arch_segment}"),
+        ))
+    }
+}
+
+/// Writes `data` to `dest` via a temp file + rename so a build interrupted
+/// mid-extraction (Ctrl-C, OOM-kill, disk full) never leaves a truncated
+/// file at `dest` for [`ensure_pinned_loader`]'s existence-based cache check
+/// to mistake for a complete, valid cache entry.
+fn write_atomic(dest: &Path, data: &[u8]) -> io::Result<()> {
+    let tmp_path = dest.with_extension(format!("{}.tmp", std::process::id()));
+    fs::write(&tmp_path, data)?;
+    match fs::rename(&tmp_path, dest) {
+        Ok(()) => Ok(()),
+        Err(e) => {
+            let _ = fs::remove_file(&tmp_path);
+            Err(e)
+        }
+    }
+}
@@ -0,0 +1,16 @@
+//! Windows-only build helpers for wiring up the WebView2 runtime.
+//!
+//! `build.rs` itself stays a thin entry point; the actual logic lives here so
+//! it can grow without turning the script into one giant `main`. The target
+//! triple mapping in the sibling `build-support` crate used to live here too,
+//! but a build script is never a `cargo test` target, so it moved out into a
+//! real lib crate where its tests can actually run.
+
+mod codegen;
+mod download;
+mod sidecar;
+mod webview2;
+
+pub use codegen::generate_runtime_check;
+pub use sidecar::{copy_sidecar_dlls, WindowsAttributes};
+pub use webview2::setup_webview2;
@@ -0,0 +1,149 @@
+//! Copies extra runtime files next to the binary, the same way
+//! [`webview2::setup_webview2`](super::webview2::setup_webview2) does for
+//! `WebView2Loader.dll`, but for any native dependency (or installer
+//! sidecar, such as the WebView2 Evergreen bootstrapper) a target needs.
+//!
+//! Configured through [`WindowsAttributes`] so `build.rs` stays declarative:
+//!
+//! ```ignore
+//! let attrs = build_support::WindowsAttributes::new()
+//!     .dll("../../vendor/some-native-lib.dll")
+//!     .dll("../../vendor/plugins/*.dll")
+//!     .bootstrapper_from_env("LEAXER_WEBVIEW2_BOOTSTRAPPER_SRC");
+//! build_support::copy_sidecar_dlls(&attrs, sdk_dir.as_deref())?;
+//! ```
+
+use std::env;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use build_support::copy_if_changed;
+
+/// Where a sidecar DLL should be resolved from.
+enum DllSource {
+    /// A path or glob pattern relative to `CARGO_MANIFEST_DIR` (or absolute).
+    Manifest(String),
+    /// A path relative to the resolved WebView2 SDK directory for the
+    /// current target arch.
+    SdkRoot(PathBuf),
+}
+
+/// Declarative list of extra DLLs to copy next to the built binary, in the
+/// spirit of `tauri_build::WindowsAttributes`.
+#[derive(Default)]
+pub struct WindowsAttributes {
+    sources: Vec<DllSource>,
+}
+
+impl WindowsAttributes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a single DLL path or glob pattern, relative to
+    /// `CARGO_MANIFEST_DIR` unless absolute.
+    pub fn dll(mut self, path_or_glob: impl Into<String>) -> Self {
+        self.sources.push(DllSource::Manifest(path_or_glob.into()));
+        self
+    }
+
+    /// Adds a DLL found relative to the WebView2 SDK directory resolved for
+    /// the current target (e.g. a companion DLL shipped alongside
+    /// `WebView2Loader.dll`).
+    pub fn sdk_dll(mut self, relative_path: impl Into<PathBuf>) -> Self {
+        self.sources.push(DllSource::SdkRoot(relative_path.into()));
+        self
+    }
+
+    /// If `env_var` is set, copies the file it points at (e.g. the
+    /// standalone WebView2 Evergreen bootstrapper) next to the binary, the
+    /// same way any other configured sidecar file is copied. A no-op when
+    /// the variable isn't set, so this stays opt-in.
+    pub fn bootstrapper_from_env(self, env_var: &str) -> Self {
+        println!("cargo:rerun-if-env-changed={env_var}");
+        match env::var(env_var) {
+            Ok(src) => self.dll(src),
+            Err(_) => self,
+        }
+    }
+}
+
+/// Copies every DLL described by `attrs` into `target/{profile}`, emitting
+/// `cargo:rerun-if-changed` for each source so the build re-runs when any of
+/// them change.
+pub fn copy_sidecar_dlls(attrs: &WindowsAttributes, sdk_dir: Option<&Path>) -> io::Result<()> {
+    let profile = env::var("PROFILE").unwrap();
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let target_dir = manifest_dir.join("target").join(&profile);
+
+    for source in &attrs.sources {
+        match source {
+            DllSource::Manifest(pattern) => {
+                for path in resolve_manifest_pattern(&manifest_dir, pattern)? {
+                    copy_one(&path, &target_dir)?;
+                }
+            }
+            DllSource::SdkRoot(relative) => {
+                let Some(sdk_dir) = sdk_dir else {
+                    println!(
+                        "cargo:warning=Skipping sdk_dll({:?}): no WebView2 SDK directory was resolved",
+                        relative
+                    );
+                    continue;
+                };
+                copy_one(&sdk_dir.join(relative), &target_dir)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn resolve_manifest_pattern(manifest_dir: &Path, pattern: &str) -> io::Result<Vec<PathBuf>> {
+    let candidate = manifest_dir.join(pattern);
+    if !pattern.contains(['*', '?', '[']) {
+        return Ok(vec![candidate]);
+    }
+
+    let pattern_str = candidate.to_string_lossy().into_owned();
+    let paths = glob::glob(&pattern_str).map_err(|e| {
+        io::Error::new(io::ErrorKind::InvalidInput, format!("invalid DLL glob pattern {pattern_str}: {e}"))
+    })?;
+
+    let mut matches = Vec::new();
+    for entry in paths {
+        let path = entry
+            .map_err(|e| io::Error::other(format!("error reading glob entry for {pattern_str}: {e}")))?;
+        matches.push(path);
+    }
+
+    if matches.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("DLL glob pattern matched no files: {pattern_str}"),
+        ));
+    }
+    Ok(matches)
+}
+
+fn copy_one(src: &Path, target_dir: &Path) -> io::Result<()> {
+    println!("cargo:rerun-if-changed={}", src.display());
+
+    if !src.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("configured sidecar DLL not found: {}", src.display()),
+        ));
+    }
+    let Some(file_name) = src.file_name() else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("sidecar DLL path has no file name: {}", src.display()),
+        ));
+    };
+
+    let dest = target_dir.join(file_name);
+    if copy_if_changed(src, &dest)? {
+        println!("cargo:warning=Copied sidecar DLL {} to {:?}", src.display(), dest);
+    }
+    Ok(())
+}
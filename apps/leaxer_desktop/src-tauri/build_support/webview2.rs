@@ -0,0 +1,131 @@
+//! Locates the `WebView2Loader` artifacts produced by `webview2-com-sys` and
+//! wires them into this crate's own link step and output directory.
+//!
+//! `webview2-com-sys` vendors the Microsoft Edge WebView2 SDK and, as part of
+//! its own build, unpacks per-architecture loader binaries under its
+//! `OUT_DIR`. It never copies them anywhere useful for *our* binary, so we
+//! have to go scavenging for them.
+
+use std::env;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use build_support::{copy_if_changed, SdkArch, TargetEnv};
+
+use super::download;
+
+/// Finds the `webview2-com-sys` build output directory by walking up from our
+/// own `OUT_DIR`. Cargo gives every crate's build script its own `OUT_DIR`
+/// under `target/.../build/<crate>-<hash>/out`, so the dependency's output
+/// lives as a sibling of one of our ancestors.
+fn find_webview2_com_sys_out_dir(our_out_dir: &Path) -> Option<PathBuf> {
+    our_out_dir
+        .ancestors()
+        .find_map(|p| {
+            let build_dir = p.join("build");
+            if !build_dir.is_dir() {
+                return None;
+            }
+            std::fs::read_dir(&build_dir).ok()?.find_map(|entry| {
+                let entry = entry.ok()?;
+                let name = entry.file_name();
+                let name = name.to_str()?;
+                if name.starts_with("webview2-com-sys-") {
+                    Some(entry.path().join("out"))
+                } else {
+                    None
+                }
+            })
+        })
+        .or_else(|| {
+            // Fallback to the previous (less robust) heuristic: our own
+            // OUT_DIR ancestors include `.../webview2-com-sys-<hash>/out`.
+            our_out_dir
+                .ancestors()
+                .find(|p| {
+                    p.file_name()
+                        .and_then(|n| n.to_str())
+                        .map(|n| n.starts_with("webview2-com-sys"))
+                        .unwrap_or(false)
+                })
+                .map(|p| p.join("out"))
+        })
+}
+
+/// Copies the loader DLL next to our binary and, for GNU targets, points the
+/// linker at the matching import library.
+///
+/// Returns the WebView2 SDK directory that was resolved (pinned download or
+/// scavenged `webview2-com-sys` output), so callers can resolve additional
+/// SDK-relative sidecar DLLs against the same location. `Ok(None)` means the
+/// target arch wasn't recognized or no SDK could be located at all;
+/// an actual copy failure is returned as `Err` so misconfiguration fails the
+/// build instead of silently shipping without the loader.
+pub fn setup_webview2() -> io::Result<Option<PathBuf>> {
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let profile = env::var("PROFILE").unwrap();
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let target_dir = Path::new(&manifest_dir).join("target").join(&profile);
+
+    let cargo_arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+    let cargo_env = env::var("CARGO_CFG_TARGET_ENV").unwrap_or_default();
+
+    let Some(arch) = SdkArch::from_cargo_arch(&cargo_arch) else {
+        println!(
+            "cargo:warning=Unrecognized CARGO_CFG_TARGET_ARCH `{cargo_arch}`, skipping WebView2Loader setup"
+        );
+        return Ok(None);
+    };
+    let toolchain = TargetEnv::from_cargo_env(&cargo_env);
+
+    // Prefer a pinned, reproducible SDK download over whatever
+    // webview2-com-sys happened to vendor; fall back to scavenging its
+    // build output if the download can't be completed (e.g. offline).
+    //
+    // GNU targets are excluded from the pinned path: the NuGet package only
+    // ships the MSVC import library, while the `.a` archive MinGW needs is
+    // produced by webview2-com-sys' own GNU build step. So GNU always uses
+    // the scavenged directory, which is the only place that `.a` exists.
+    let sdk_dir = match toolchain {
+        TargetEnv::Msvc => download::ensure_pinned_loader(&out_dir, arch).map(|pinned| pinned.dir),
+        TargetEnv::Gnu => None,
+    };
+    let sdk_dir = match sdk_dir {
+        Some(dir) => dir,
+        None => match find_webview2_com_sys_out_dir(&out_dir) {
+            Some(sys_out_dir) => sys_out_dir.join(arch.sdk_folder()),
+            None => {
+                println!("cargo:warning=Could not locate webview2-com-sys build output, skipping WebView2Loader copy");
+                return Ok(None);
+            }
+        },
+    };
+    let src_dll = sdk_dir.join("WebView2Loader.dll");
+
+    if !src_dll.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("WebView2Loader.dll not found at {src_dll:?}"),
+        ));
+    }
+    let dest = target_dir.join("WebView2Loader.dll");
+    if copy_if_changed(&src_dll, &dest)? {
+        println!("cargo:warning=Copied WebView2Loader.dll ({}) to {:?}", arch.sdk_folder(), dest);
+    }
+
+    match toolchain {
+        TargetEnv::Msvc => {
+            // webview2-com-sys emits its own `cargo:rustc-link-lib` for the
+            // MSVC import library; nothing extra to do here.
+        }
+        TargetEnv::Gnu => {
+            // MinGW can't link the MSVC `WebView2Loader.dll.lib` import
+            // library directly. webview2-com-sys' GNU build converts it to a
+            // `.a` archive alongside the DLL; point the linker at it.
+            println!("cargo:rustc-link-search=native={}", sdk_dir.display());
+            println!("cargo:rustc-link-lib=dylib=WebView2Loader");
+        }
+    }
+
+    Ok(Some(sdk_dir))
+}